@@ -1,5 +1,11 @@
-use regex::{Captures, Regex};
-use rusty_advent_2024::utils::{self, lines_from_file};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while_m_n},
+    combinator::{map, map_res, value},
+    sequence::{delimited, separated_pair},
+    IResult,
+};
+use rusty_advent_2024::utils::parsing;
 
 fn main() {
     println!("Answer to part 1:");
@@ -8,38 +14,107 @@ fn main() {
     println!("{}", part2("input/input03.txt"));
 }
 
-fn compute_sum(row: &str) -> i32 {
-    let pattern: Regex = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").expect("Regex pattern invalid.");
-    pattern
-        .captures_iter(&row)
-        .map(|captures| -> (i32, i32) {
-            (
-                captures
-                    .get(1)
-                    .expect("Failed to capture group 1.")
-                    .as_str()
-                    .parse::<i32>()
-                    .expect("Failed to parse match 1."),
-                captures
-                    .get(2)
-                    .expect("Failed to capture group 2.")
-                    .as_str()
-                    .parse::<i32>()
-                    .expect("Failed to parse match 2."),
-            )
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Instruction {
+    Mul(i32, i32),
+    Do,
+    Dont,
+}
+
+/// A `mul` operand is 1-3 digits, per the puzzle spec (mirrors the baseline's `\d{1,3}` regex).
+fn operand(input: &str) -> IResult<&str, i32> {
+    map_res(take_while_m_n(1, 3, |c: char| c.is_ascii_digit()), str::parse)(input)
+}
+
+fn mul(input: &str) -> IResult<&str, Instruction> {
+    map(
+        delimited(
+            tag("mul("),
+            separated_pair(operand, tag(","), operand),
+            tag(")"),
+        ),
+        |(num1, num2)| Instruction::Mul(num1, num2),
+    )(input)
+}
+
+fn do_instruction(input: &str) -> IResult<&str, Instruction> {
+    value(Instruction::Do, tag("do()"))(input)
+}
+
+fn dont_instruction(input: &str) -> IResult<&str, Instruction> {
+    value(Instruction::Dont, tag("don't()"))(input)
+}
+
+fn instruction(input: &str) -> IResult<&str, Instruction> {
+    alt((mul, do_instruction, dont_instruction))(input)
+}
+
+/// Scans `input` left to right, keeping every `mul`/`do`/`don't` token found and
+/// skipping a byte at a time over anything that isn't a recognized instruction.
+fn tokenize(input: &str) -> Vec<Instruction> {
+    let mut instructions = Vec::new();
+    let mut rest = input;
+    while !rest.is_empty() {
+        match instruction(rest) {
+            Ok((remainder, token)) => {
+                instructions.push(token);
+                rest = remainder;
+            }
+            Err(_) => rest = &rest[1..],
+        }
+    }
+    instructions
+}
+
+fn sum_muls(instructions: &[Instruction]) -> i32 {
+    instructions
+        .iter()
+        .filter_map(|instruction| match instruction {
+            Instruction::Mul(num1, num2) => Some(num1 * num2),
+            _ => None,
         })
-        .map(|(num1, num2)| num1 * num2)
         .sum()
 }
 
+fn sum_enabled_muls(instructions: &[Instruction]) -> i32 {
+    let mut enabled = true;
+    let mut sum = 0;
+    for instruction in instructions {
+        match instruction {
+            Instruction::Do => enabled = true,
+            Instruction::Dont => enabled = false,
+            Instruction::Mul(num1, num2) if enabled => sum += num1 * num2,
+            Instruction::Mul(_, _) => (),
+        }
+    }
+    sum
+}
+
+fn compute_sum(row: &str) -> i32 {
+    sum_muls(&tokenize(row))
+}
+
+fn rows(path: &str) -> Vec<Vec<char>> {
+    parsing::parse_file(path, parsing::grid).expect("Failed to parse input file.")
+}
+
+fn joined_input(path: &str) -> String {
+    rows(path)
+        .into_iter()
+        .map(|row| row.into_iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 fn part1(path: &str) -> i32 {
-    lines_from_file(path)
-        .map(|line| compute_sum(line.unwrap().as_str()))
+    rows(path)
+        .into_iter()
+        .map(|row| compute_sum(&row.into_iter().collect::<String>()))
         .sum()
 }
 
-fn part2(_path: &str) -> i32 {
-    0
+fn part2(path: &str) -> i32 {
+    sum_enabled_muls(&tokenize(&joined_input(path)))
 }
 
 #[cfg(test)]
@@ -56,6 +131,11 @@ mod tests {
 
     #[test]
     fn test_part2() {
-        assert!(part2("input/input03.txt.test1") == 0);
+        assert!(
+            sum_enabled_muls(&tokenize(
+                "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))"
+            )) == 48
+        );
+        assert!(part2("input/input03.txt.test2") == 48);
     }
-}
\ No newline at end of file
+}
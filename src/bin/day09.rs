@@ -1,7 +1,8 @@
 use std::cmp;
 
 use itertools::Itertools;
-use rusty_advent_2024::utils::lines_from_file;
+use nom::{character::complete::not_line_ending, combinator::map, IResult};
+use rusty_advent_2024::utils::parsing;
 
 #[derive(Clone, Copy, Debug)]
 enum DataBlock {
@@ -111,6 +112,68 @@ fn compressed(harddisk: &Vec<DataBlock>) -> Vec<DataBlock> {
     compressed_harddisk
 }
 
+/// Whole-file compaction: each file, in decreasing id order, is moved at most once into the
+/// leftmost free span that both fits it and lies to its left; files that don't fit anywhere
+/// stay put. `blocks` remains in positional order throughout, so `checksum` keeps working
+/// unchanged, and a file's old location is left behind as `Free` rather than removed so the
+/// blocks after it keep their positions.
+fn compacted(harddisk: &Vec<DataBlock>) -> Vec<DataBlock> {
+    let mut blocks = harddisk.clone();
+
+    let max_id = blocks
+        .iter()
+        .filter_map(|block| match block {
+            DataBlock::File { id, .. } => Some(*id),
+            DataBlock::Free { .. } => None,
+        })
+        .max()
+        .unwrap_or(0);
+
+    for id in (0..=max_id).rev() {
+        let file_idx = match blocks.iter().position(|block| {
+            matches!(block, DataBlock::File { id: file_id, .. } if *file_id == id)
+        }) {
+            Some(idx) => idx,
+            None => continue,
+        };
+
+        let file_size = match blocks[file_idx] {
+            DataBlock::File { size, .. } => size,
+            DataBlock::Free { .. } => unreachable!("file_idx was found via a File match"),
+        };
+
+        let free_idx = blocks[..file_idx]
+            .iter()
+            .position(|block| matches!(block, DataBlock::Free { size } if *size >= file_size));
+
+        let free_idx = match free_idx {
+            Some(idx) => idx,
+            None => continue,
+        };
+
+        let free_size = match blocks[free_idx] {
+            DataBlock::Free { size } => size,
+            DataBlock::File { .. } => unreachable!("free_idx was found via a Free match"),
+        };
+
+        blocks[free_idx] = DataBlock::File { id, size: file_size };
+        let moved_file_idx = if free_size > file_size {
+            blocks.insert(
+                free_idx + 1,
+                DataBlock::Free {
+                    size: free_size - file_size,
+                },
+            );
+            file_idx + 1
+        } else {
+            file_idx
+        };
+        blocks[moved_file_idx] = DataBlock::Free { size: file_size };
+    }
+
+    blocks
+}
+
 fn blocks_from_string(string: String) -> Vec<DataBlock> {
     string
         .split("")
@@ -126,6 +189,16 @@ fn blocks_from_string(string: String) -> Vec<DataBlock> {
         .collect_vec()
 }
 
+/// The puzzle input is a single line of run-length digits, e.g. `"2333133121414131402"`.
+fn disk_map(input: &str) -> IResult<&str, String> {
+    map(not_line_ending, str::to_string)(input)
+}
+
+fn blocks_from_file(path: &str) -> Vec<DataBlock> {
+    let string = parsing::parse_file(path, disk_map).expect("Failed to parse input file.");
+    blocks_from_string(string)
+}
+
 fn main() {
     println!("Answer to part 1:");
     println!("{}", part1("input/input09.txt"));
@@ -134,20 +207,19 @@ fn main() {
 }
 
 fn part1(path: &str) -> u128 {
-    let string = lines_from_file(path)
-        .map(|line| line.unwrap())
-        .find_or_first(|_| true)
-        .expect("No input found.");
-
-    let blocks = blocks_from_string(string);
+    let blocks = blocks_from_file(path);
 
     let compressed_blocks = compressed(&blocks);
 
     checksum(&compressed_blocks)
 }
 
-fn part2(_path: &str) -> u128 {
-    0
+fn part2(path: &str) -> u128 {
+    let blocks = blocks_from_file(path);
+
+    let compacted_blocks = compacted(&blocks);
+
+    checksum(&compacted_blocks)
 }
 
 #[cfg(test)]
@@ -184,6 +256,17 @@ mod tests {
         assert!(checksum(&hdd4) == 3 + 4 + 5);
     }
 
+    #[test]
+    fn test_tiny_disks_compacted() {
+        // "12345": 0..111....22222 -> no whole file fits in the gaps to its left, unchanged
+        let hdd = compacted(&blocks_from_string(String::from("12345")));
+        assert!(
+            checksum(&hdd)
+                == (partial_checksum(0, 0, 1) + partial_checksum(1, 3, 3) + partial_checksum(2, 10, 5))
+                    as u128
+        );
+    }
+
     #[test]
     fn test_part1() {
         assert!(part1("input/input09.txt.test1") == 1928);
@@ -191,6 +274,6 @@ mod tests {
 
     #[test]
     fn test_part2() {
-        assert!(part2("input/input09.txt.test1") == 0);
+        assert!(part2("input/input09.txt.test1") == 2858);
     }
 }
@@ -13,43 +13,238 @@ pub mod utils {
         BufReader::new(file).lines()
     }
 
+    /// Re-parses a number extracted by a nom parser as a caller-chosen `T`. `two_columns_from_file`
+    /// and `rows_from_file` are generic over `T: FromStr` for historical reasons, while the nom
+    /// parsers below only ever need to recognise integers, so we round-trip through `i64`.
+    fn int_as<T: FromStr>(value: i64) -> T
+    where
+        T::Err: Debug,
+    {
+        value
+            .to_string()
+            .parse()
+            .unwrap_or_else(|_| panic!("Failed to parse: {}.", value))
+    }
+
     pub fn two_columns_from_file<T: FromStr>(path: &str) -> (Vec<T>, Vec<T>)
     where
         T::Err: Debug,
     {
-        lines_from_file(path)
-            .map(|line| -> (T, T) {
-                line.unwrap()
-                    .split_whitespace()
-                    .map(|word| word.parse().expect(&format!("Failed to parse: {}.", word)))
-                    .collect_tuple()
-                    .expect("Each line must contain exactly two elements.")
-            })
-            .unzip()
+        parsing::parse_file(
+            path,
+            parsing::lines(|line| {
+                nom::sequence::separated_pair(
+                    parsing::int,
+                    nom::character::complete::space1,
+                    parsing::int,
+                )(line)
+            }),
+        )
+        .expect("Failed to parse input file.")
+        .into_iter()
+        .map(|(a, b): (i64, i64)| (int_as(a), int_as(b)))
+        .unzip()
     }
 
     pub fn rows_from_file<T: FromStr>(path: &str) -> Vec<Vec<T>>
     where
         T::Err: Debug,
     {
-        lines_from_file(path)
-            .map(|line| -> Vec<T> {
-                line.unwrap()
-                    .split_whitespace()
-                    .map(|word: &str| {
-                        word.parse::<T>()
-                            .expect(&format!("Failed to parse: {}.", word))
-                    })
-                    .collect_vec()
-            })
+        parsing::parse_file(path, parsing::lines(parsing::separated_ints))
+            .expect("Failed to parse input file.")
+            .into_iter()
+            .map(|row| row.into_iter().map(int_as).collect_vec())
             .collect_vec()
     }
+
+    /// nom-based input parsers that return a `ParseError` instead of panicking.
+    ///
+    /// Each day's binary is expected to write a small nom parser for its own structured
+    /// input and hand it to [`parse_file`], rather than hand-rolling a regex or relying on
+    /// whitespace splitting. [`two_columns_from_file`] and [`rows_from_file`] are kept as
+    /// thin wrappers over these parsers for backward compatibility with existing callers.
+    pub mod parsing {
+        use std::fmt;
+
+        use nom::{
+            branch::alt,
+            character::complete::{anychar, char, digit1, line_ending, none_of, space0, space1},
+            combinator::{all_consuming, map, map_res, opt, recognize, value},
+            multi::{many0, many1, separated_list0, separated_list1},
+            sequence::{pair, preceded},
+            IResult,
+        };
+
+        /// Errors produced while reading and parsing an input file.
+        #[derive(Debug)]
+        pub enum ParseError {
+            Io(std::io::Error),
+            Nom(String),
+        }
+
+        impl fmt::Display for ParseError {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    ParseError::Io(err) => write!(f, "Failed to read input file: {err}"),
+                    ParseError::Nom(msg) => write!(f, "Failed to parse input: {msg}"),
+                }
+            }
+        }
+
+        impl std::error::Error for ParseError {}
+
+        impl From<std::io::Error> for ParseError {
+            fn from(err: std::io::Error) -> Self {
+                ParseError::Io(err)
+            }
+        }
+
+        /// Parses a single signed integer token, e.g. `-42` or `17`.
+        pub fn int(input: &str) -> IResult<&str, i64> {
+            map_res(recognize(pair(opt(char('-')), digit1)), str::parse)(input)
+        }
+
+        /// Parses a single unsigned integer token, e.g. `17`.
+        pub fn uint(input: &str) -> IResult<&str, u64> {
+            map_res(digit1, str::parse)(input)
+        }
+
+        /// Extracts every signed integer found in `input`, skipping over any other
+        /// characters in between (commas, letters, colons, a `-` not followed by a digit, ...).
+        pub fn ints(input: &str) -> IResult<&str, Vec<i64>> {
+            map(
+                many0(alt((map(int, Some), value(None, anychar)))),
+                |tokens: Vec<Option<i64>>| tokens.into_iter().flatten().collect(),
+            )(input)
+        }
+
+        /// Parses a sequence of signed integers separated by run(s) of whitespace,
+        /// e.g. `"1 2   3"`.
+        pub fn separated_ints(input: &str) -> IResult<&str, Vec<i64>> {
+            separated_list1(space1, preceded(space0, int))(input)
+        }
+
+        /// A line-by-line parser, genuinely higher-ranked over its input lifetime (rather than
+        /// `impl FnMut`, whose opaque return type can only be generic over a single lifetime).
+        pub type LinesParser<T> = Box<dyn for<'r> FnMut(&'r str) -> IResult<&'r str, Vec<T>>>;
+
+        /// Applies `parser` to every line of `input`, joined by line endings. The result can be
+        /// handed straight to [`parse_file`], whose `parser` parameter is higher-ranked too.
+        pub fn lines<T: 'static>(
+            mut parser: impl for<'r> FnMut(&'r str) -> IResult<&'r str, T> + 'static,
+        ) -> LinesParser<T> {
+            Box::new(move |input| separated_list0(line_ending, &mut parser)(input))
+        }
+
+        /// Parses a rectangular character grid: one row per line, one `char` per column.
+        pub fn grid(input: &str) -> IResult<&str, Vec<Vec<char>>> {
+            separated_list1(line_ending, many1(none_of("\r\n")))(input)
+        }
+
+        /// Reads `path` and runs `parser` over its full contents (minus any trailing
+        /// line endings), returning a descriptive [`ParseError`] instead of panicking on
+        /// I/O or parse failure. `parser` is required to consume the input in full: a
+        /// malformed or unexpected line mid-file would otherwise be silently dropped along
+        /// with everything after it rather than surfacing as an error.
+        pub fn parse_file<T>(
+            path: &str,
+            parser: impl FnMut(&str) -> IResult<&str, T>,
+        ) -> Result<T, ParseError> {
+            let contents = std::fs::read_to_string(path)?;
+            let trimmed = contents.trim_end_matches(['\n', '\r']);
+            return all_consuming(parser)(trimmed)
+                .map(|(_, value)| value)
+                .map_err(|err| ParseError::Nom(err.to_string()));
+        }
+
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+
+            #[test]
+            fn test_int() {
+                assert!(int("42").unwrap().1 == 42);
+                assert!(int("-17 rest").unwrap() == (" rest", -17));
+                assert!(int("nope").is_err());
+            }
+
+            #[test]
+            fn test_uint() {
+                assert!(uint("8").unwrap().1 == 8);
+                assert!(uint("-8").is_err());
+            }
+
+            #[test]
+            fn test_ints() {
+                assert!(ints("3   4, -5 and 6").unwrap().1 == vec![3, 4, -5, 6]);
+                assert!(ints("no numbers here").unwrap().1 == Vec::<i64>::new());
+            }
+
+            #[test]
+            fn test_separated_ints() {
+                assert!(separated_ints("1 2   3").unwrap().1 == vec![1, 2, 3]);
+            }
+
+            #[test]
+            fn test_grid() {
+                assert!(grid("ab\ncd").unwrap().1 == vec![vec!['a', 'b'], vec!['c', 'd']]);
+            }
+
+            #[test]
+            fn test_lines() {
+                let mut parser = lines(int);
+                assert!(parser("1\n2\n3").unwrap().1 == vec![1, 2, 3]);
+            }
+
+            #[test]
+            fn test_parse_file() {
+                let path = std::env::temp_dir().join("rusty_advent_2024_parsing_test.txt");
+                std::fs::write(&path, "10 20\n30 40").expect("Failed to write test fixture.");
+
+                let result = parse_file(
+                    path.to_str().unwrap(),
+                    lines(|line| {
+                        nom::sequence::separated_pair(int, nom::character::complete::space1, int)(
+                            line,
+                        )
+                    }),
+                )
+                .expect("Failed to parse test fixture.");
+
+                std::fs::remove_file(&path).expect("Failed to remove test fixture.");
+                assert!(result == vec![(10, 20), (30, 40)]);
+            }
+
+            #[test]
+            fn test_parse_file_rejects_unconsumed_remainder() {
+                // separated_list0 inside `lines` stops (rather than erroring) at the first
+                // line that doesn't match, so without `all_consuming` this would silently
+                // return `[(1, 2), (3, 4)]` and drop the rest of the file.
+                let path = std::env::temp_dir().join("rusty_advent_2024_parsing_test_bad.txt");
+                std::fs::write(&path, "1 2\n3 4\nXYZ not numbers\n5 6")
+                    .expect("Failed to write test fixture.");
+
+                let result = parse_file(
+                    path.to_str().unwrap(),
+                    lines(|line| {
+                        nom::sequence::separated_pair(int, nom::character::complete::space1, int)(
+                            line,
+                        )
+                    }),
+                );
+
+                std::fs::remove_file(&path).expect("Failed to remove test fixture.");
+                assert!(matches!(result, Err(ParseError::Nom(_))));
+            }
+        }
+    }
 }
 
 pub mod maps {
     use itertools::Itertools;
     use std::{
-        collections::{HashSet, VecDeque},
+        cmp::Reverse,
+        collections::{BinaryHeap, HashMap, HashSet, VecDeque},
         hash::Hash,
         io::{BufRead, Lines},
     };
@@ -79,7 +274,7 @@ pub mod maps {
     #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
     pub struct Position(pub i32, pub i32);
 
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
     pub struct ValidPosition(pub usize, pub usize);
 
     #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
@@ -115,12 +310,34 @@ pub mod maps {
             ]
         }
 
+        pub fn diagonal_neighbours(&self) -> Vec<Position> {
+            vec![
+                Position(self.0 + 1, self.1 + 1),
+                Position(self.0 + 1, self.1 - 1),
+                Position(self.0 - 1, self.1 + 1),
+                Position(self.0 - 1, self.1 - 1),
+            ]
+        }
+
+        pub fn neighbours_8(&self) -> Vec<Position> {
+            let mut neighbours = self.neighbours();
+            neighbours.extend(self.diagonal_neighbours());
+            neighbours
+        }
+
         pub fn valid_neighbours(&self, bounds: &Bounds) -> HashSet<ValidPosition> {
             self.neighbours()
                 .into_iter()
                 .filter_map(|neib| neib.in_bounds(bounds))
                 .collect()
         }
+
+        pub fn valid_neighbours_8(&self, bounds: &Bounds) -> HashSet<ValidPosition> {
+            self.neighbours_8()
+                .into_iter()
+                .filter_map(|neib| neib.in_bounds(bounds))
+                .collect()
+        }
     }
 
     impl ValidPosition {
@@ -131,6 +348,40 @@ pub mod maps {
                 .filter_map(|neib| neib.in_bounds(bounds))
                 .collect()
         }
+
+        pub fn valid_neighbours_8(&self, bounds: &Bounds) -> HashSet<ValidPosition> {
+            let _pos: &Position = &(*self).into();
+            _pos.neighbours_8()
+                .into_iter()
+                .filter_map(|neib| neib.in_bounds(bounds))
+                .collect()
+        }
+    }
+
+    /// Selects which cells count as adjacent to a given position, so callers like
+    /// [`Map2D::contiguous_region`] can opt into 8-connected regions (blob/constellation
+    /// grouping) instead of only the plain orthogonal flood fill.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+    pub enum Adjacency {
+        Orthogonal,
+        Diagonal,
+        All,
+    }
+
+    impl Adjacency {
+        fn neighbours(&self, pos: &ValidPosition, bounds: &Bounds) -> HashSet<ValidPosition> {
+            match self {
+                Adjacency::Orthogonal => pos.valid_neighbours(bounds),
+                Adjacency::Diagonal => {
+                    let _pos: Position = (*pos).into();
+                    _pos.diagonal_neighbours()
+                        .into_iter()
+                        .filter_map(|neib| neib.in_bounds(bounds))
+                        .collect()
+                }
+                Adjacency::All => pos.valid_neighbours_8(bounds),
+            }
+        }
     }
 
     impl<T: HasCharConverter, B: BufRead> From<Lines<B>> for Map2D<T> {
@@ -165,7 +416,11 @@ pub mod maps {
                 .collect()
         }
 
-        pub fn contiguous_region(&self, &pos: &ValidPosition) -> HashSet<ValidPosition> {
+        pub fn contiguous_region(
+            &self,
+            &pos: &ValidPosition,
+            adjacency: Adjacency,
+        ) -> HashSet<ValidPosition> {
             let mut visited: HashSet<ValidPosition> = HashSet::new();
             let mut to_visit: VecDeque<ValidPosition> = VecDeque::new();
             to_visit.push_back(pos);
@@ -176,7 +431,7 @@ pub mod maps {
                     continue;
                 }
 
-                for neib in next_pos.valid_neighbours(&self.bounds) {
+                for neib in adjacency.neighbours(&next_pos, &self.bounds) {
                     if self.value(&neib) == target_value {
                         to_visit.push_back(neib);
                     }
@@ -187,7 +442,189 @@ pub mod maps {
         }
     }
 
-    #[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+    impl<T> Map2D<T> {
+        /// Reconstructs the path ending at `goal` by walking `came_from` back to a node
+        /// that has no predecessor recorded (the search's start node).
+        fn reconstruct_path(
+            came_from: &HashMap<ValidPosition, ValidPosition>,
+            goal: ValidPosition,
+        ) -> Vec<ValidPosition> {
+            let mut path = vec![goal];
+            let mut current = goal;
+            while let Some(&previous) = came_from.get(&current) {
+                path.push(previous);
+                current = previous;
+            }
+            path.reverse();
+            path
+        }
+
+        /// Dijkstra's algorithm over the grid: finds the least-cost path from `start` to
+        /// `goal`, where `cost(from, to)` gives the price of stepping between adjacent
+        /// cells or `None` if the move is blocked. Returns the total cost and the path.
+        pub fn dijkstra(
+            &self,
+            start: ValidPosition,
+            goal: ValidPosition,
+            cost: impl Fn(&ValidPosition, &ValidPosition) -> Option<u32>,
+        ) -> Option<(u32, Vec<ValidPosition>)> {
+            let mut dist: HashMap<ValidPosition, u32> = HashMap::from([(start, 0)]);
+            let mut came_from: HashMap<ValidPosition, ValidPosition> = HashMap::new();
+            let mut to_visit: BinaryHeap<(Reverse<u32>, ValidPosition)> = BinaryHeap::new();
+            to_visit.push((Reverse(0), start));
+
+            while let Some((Reverse(current_dist), pos)) = to_visit.pop() {
+                if pos == goal {
+                    return Some((current_dist, Self::reconstruct_path(&came_from, goal)));
+                }
+
+                if current_dist > *dist.get(&pos).unwrap_or(&u32::MAX) {
+                    continue;
+                }
+
+                for neib in pos.valid_neighbours(&self.bounds) {
+                    if let Some(step_cost) = cost(&pos, &neib) {
+                        let next_dist = current_dist + step_cost;
+                        if next_dist < *dist.get(&neib).unwrap_or(&u32::MAX) {
+                            dist.insert(neib, next_dist);
+                            came_from.insert(neib, pos);
+                            to_visit.push((Reverse(next_dist), neib));
+                        }
+                    }
+                }
+            }
+
+            None
+        }
+
+        /// A* search: like [`Map2D::dijkstra`], but the priority queue is ordered by
+        /// `g_cost + heuristic(pos, goal)` rather than `g_cost` alone. `heuristic` must be
+        /// admissible (never overestimate the true remaining cost); passing a heuristic that
+        /// always returns `0` makes this equivalent to plain Dijkstra.
+        pub fn a_star(
+            &self,
+            start: ValidPosition,
+            goal: ValidPosition,
+            cost: impl Fn(&ValidPosition, &ValidPosition) -> Option<u32>,
+            heuristic: impl Fn(&ValidPosition, &ValidPosition) -> u32,
+        ) -> Option<(u32, Vec<ValidPosition>)> {
+            let mut g_cost: HashMap<ValidPosition, u32> = HashMap::from([(start, 0)]);
+            let mut came_from: HashMap<ValidPosition, ValidPosition> = HashMap::new();
+            let mut to_visit: BinaryHeap<(Reverse<u32>, Reverse<u32>, ValidPosition)> =
+                BinaryHeap::new();
+            to_visit.push((Reverse(heuristic(&start, &goal)), Reverse(0), start));
+
+            while let Some((_, Reverse(current_g), pos)) = to_visit.pop() {
+                if current_g > *g_cost.get(&pos).unwrap_or(&u32::MAX) {
+                    continue;
+                }
+
+                if pos == goal {
+                    return Some((current_g, Self::reconstruct_path(&came_from, goal)));
+                }
+
+                for neib in pos.valid_neighbours(&self.bounds) {
+                    if let Some(step_cost) = cost(&pos, &neib) {
+                        let next_g = current_g + step_cost;
+                        if next_g < *g_cost.get(&neib).unwrap_or(&u32::MAX) {
+                            g_cost.insert(neib, next_g);
+                            came_from.insert(neib, pos);
+                            to_visit.push((
+                                Reverse(next_g + heuristic(&neib, &goal)),
+                                Reverse(next_g),
+                                neib,
+                            ));
+                        }
+                    }
+                }
+            }
+
+            None
+        }
+
+        /// Reconstructs the state path ending at `goal`, mirroring [`Map2D::reconstruct_path`]
+        /// but over `(ValidPosition, Direction)` states rather than bare positions.
+        fn reconstruct_state_path(
+            came_from: &HashMap<(ValidPosition, Direction), (ValidPosition, Direction)>,
+            goal: (ValidPosition, Direction),
+        ) -> Vec<(ValidPosition, Direction)> {
+            let mut path = vec![goal];
+            let mut current = goal;
+            while let Some(&previous) = came_from.get(&current) {
+                path.push(previous);
+                current = previous;
+            }
+            path.reverse();
+            path
+        }
+
+        /// Dijkstra over `(ValidPosition, Direction)` states rather than bare positions, for
+        /// mazes where turning has its own cost distinct from moving (e.g. the "reindeer
+        /// maze" style problems). From each state the successors are: step forward one cell
+        /// in the current direction (cost `step_cost`, only if the target cell is in bounds
+        /// and `passable`), and turn left or right in place (cost `turn_cost` each, using
+        /// [`Direction::turned_left`]/[`Direction::turned_right`]). Returns the minimum cost
+        /// to reach `goal` in any orientation, plus the path of states.
+        pub fn shortest_path_with_orientation(
+            &self,
+            start: ValidPosition,
+            start_dir: Direction,
+            goal: ValidPosition,
+            passable: impl Fn(&ValidPosition) -> bool,
+            step_cost: u32,
+            turn_cost: u32,
+        ) -> Option<(u32, Vec<(ValidPosition, Direction)>)> {
+            let start_state = (start, start_dir);
+            let mut dist: HashMap<(ValidPosition, Direction), u32> =
+                HashMap::from([(start_state, 0)]);
+            let mut came_from: HashMap<(ValidPosition, Direction), (ValidPosition, Direction)> =
+                HashMap::new();
+            let mut to_visit: BinaryHeap<(Reverse<u32>, ValidPosition, Direction)> =
+                BinaryHeap::new();
+            to_visit.push((Reverse(0), start, start_dir));
+
+            while let Some((Reverse(current_dist), pos, dir)) = to_visit.pop() {
+                let state = (pos, dir);
+
+                if pos == goal {
+                    return Some((
+                        current_dist,
+                        Self::reconstruct_state_path(&came_from, state),
+                    ));
+                }
+
+                if current_dist > *dist.get(&state).unwrap_or(&u32::MAX) {
+                    continue;
+                }
+
+                if let Some(next_pos) = Position::from(pos).step(&dir).in_bounds(&self.bounds) {
+                    if passable(&next_pos) {
+                        let next_state = (next_pos, dir);
+                        let next_dist = current_dist + step_cost;
+                        if next_dist < *dist.get(&next_state).unwrap_or(&u32::MAX) {
+                            dist.insert(next_state, next_dist);
+                            came_from.insert(next_state, state);
+                            to_visit.push((Reverse(next_dist), next_pos, dir));
+                        }
+                    }
+                }
+
+                for turned in [dir.turned_left(), dir.turned_right()] {
+                    let next_state = (pos, turned);
+                    let next_dist = current_dist + turn_cost;
+                    if next_dist < *dist.get(&next_state).unwrap_or(&u32::MAX) {
+                        dist.insert(next_state, next_dist);
+                        came_from.insert(next_state, state);
+                        to_visit.push((Reverse(next_dist), pos, turned));
+                    }
+                }
+            }
+
+            None
+        }
+    }
+
+    #[derive(PartialEq, Eq, Hash, Copy, Clone, Debug, PartialOrd, Ord)]
     pub enum Direction {
         UP,
         RIGHT,
@@ -259,4 +696,151 @@ pub mod maps {
             }
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        /// A 3x3 grid of '.' (open) and '#' (wall):
+        /// ```text
+        /// . . .
+        /// . # .
+        /// . . .
+        /// ```
+        fn test_grid() -> Map2D<char> {
+            Map2D {
+                data: vec![
+                    vec!['.', '.', '.'],
+                    vec!['.', '#', '.'],
+                    vec!['.', '.', '.'],
+                ],
+                bounds: Bounds(3, 3),
+            }
+        }
+
+        fn open_step_cost(
+            grid: &Map2D<char>,
+        ) -> impl Fn(&ValidPosition, &ValidPosition) -> Option<u32> + '_ {
+            |_from, to| {
+                if *grid.value(to) == '#' {
+                    None
+                } else {
+                    Some(1)
+                }
+            }
+        }
+
+        fn manhattan(a: &ValidPosition, b: &ValidPosition) -> u32 {
+            a.0.abs_diff(b.0) as u32 + a.1.abs_diff(b.1) as u32
+        }
+
+        #[test]
+        fn test_dijkstra_blocked_path() {
+            let grid = test_grid();
+            let (cost, path) = grid
+                .dijkstra(ValidPosition(0, 0), ValidPosition(2, 2), open_step_cost(&grid))
+                .expect("A path around the wall exists.");
+
+            assert!(cost == 4);
+            assert!(path.first() == Some(&ValidPosition(0, 0)));
+            assert!(path.last() == Some(&ValidPosition(2, 2)));
+            assert!(!path.contains(&ValidPosition(1, 1)));
+        }
+
+        #[test]
+        fn test_dijkstra_no_path() {
+            // A single free cell surrounded by walls has nothing reachable beyond itself.
+            let grid = Map2D {
+                data: vec![
+                    vec!['#', '#', '#'],
+                    vec!['#', '.', '#'],
+                    vec!['#', '#', '#'],
+                ],
+                bounds: Bounds(3, 3),
+            };
+
+            assert!(grid
+                .dijkstra(ValidPosition(1, 1), ValidPosition(0, 0), open_step_cost(&grid))
+                .is_none());
+        }
+
+        #[test]
+        fn test_a_star_agrees_with_dijkstra() {
+            let grid = test_grid();
+            let dijkstra_result = grid
+                .dijkstra(ValidPosition(0, 0), ValidPosition(2, 2), open_step_cost(&grid))
+                .expect("A path around the wall exists.");
+            let a_star_result = grid
+                .a_star(
+                    ValidPosition(0, 0),
+                    ValidPosition(2, 2),
+                    open_step_cost(&grid),
+                    manhattan,
+                )
+                .expect("A path around the wall exists.");
+
+            assert!(a_star_result.0 == dijkstra_result.0);
+        }
+
+        #[test]
+        fn test_shortest_path_with_orientation_avoids_costly_turns() {
+            // A straight 1x5 corridor: turning around to backtrack costs more than just
+            // walking forward, so the cheapest path to the far end never turns.
+            let grid = Map2D {
+                data: vec![vec!['.']; 5],
+                bounds: Bounds(5, 1),
+            };
+            let passable = |pos: &ValidPosition| *grid.value(pos) == '.';
+
+            let (cost, path) = grid
+                .shortest_path_with_orientation(
+                    ValidPosition(0, 0),
+                    Direction::RIGHT,
+                    ValidPosition(4, 0),
+                    passable,
+                    1,
+                    1000,
+                )
+                .expect("The corridor is fully passable.");
+
+            assert!(cost == 4);
+            assert!(path.iter().all(|(_, dir)| *dir == Direction::RIGHT));
+        }
+
+        #[test]
+        fn test_diagonal_neighbours() {
+            let neighbours = Position(1, 1).diagonal_neighbours();
+            assert!(neighbours.contains(&Position(2, 2)));
+            assert!(neighbours.contains(&Position(2, 0)));
+            assert!(neighbours.contains(&Position(0, 2)));
+            assert!(neighbours.contains(&Position(0, 0)));
+            assert!(neighbours.len() == 4);
+        }
+
+        #[test]
+        fn test_neighbours_8() {
+            let neighbours = Position(1, 1).neighbours_8();
+            assert!(neighbours.len() == 8);
+            assert!(neighbours.contains(&Position(1, 0)));
+            assert!(neighbours.contains(&Position(2, 2)));
+        }
+
+        #[test]
+        fn test_contiguous_region_diagonal_adjacency() {
+            // Two '#' cells touching only at a corner: orthogonal adjacency keeps them
+            // apart, diagonal adjacency merges them into one region.
+            let grid = Map2D {
+                data: vec![vec!['#', '.'], vec!['.', '#']],
+                bounds: Bounds(2, 2),
+            };
+
+            let orthogonal_region =
+                grid.contiguous_region(&ValidPosition(0, 0), Adjacency::Orthogonal);
+            assert!(orthogonal_region.len() == 1);
+
+            let diagonal_region = grid.contiguous_region(&ValidPosition(0, 0), Adjacency::Diagonal);
+            assert!(diagonal_region.contains(&ValidPosition(1, 1)));
+            assert!(diagonal_region.len() == 2);
+        }
+    }
 }